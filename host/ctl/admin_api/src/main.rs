@@ -1,13 +1,15 @@
 use anyhow::Result;
 use axum::{
     body::Body, extract::State, http::header, http::StatusCode, response::IntoResponse,
-    routing::get, Json, Router,
+    routing::get, routing::post, Json, Router,
 };
 use azure_storage_offload_core::metrics::Metrics;
 use azure_storage_offload_core::{metrics, tracing as core_tracing};
 use prometheus::{Encoder, TextEncoder};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::env;
 use std::net::SocketAddr;
+use tower_http::trace::TraceLayer;
 use tracing::{error, info};
 
 #[derive(Serialize)]
@@ -22,15 +24,22 @@ struct AppState {
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let _guard = core_tracing::init_tracing("admin_api")?;
+    let _guard = core_tracing::init_tracing("admin_api", core_tracing::TracingConfig::from_env())?;
     let metrics = metrics::metrics();
 
     let state = AppState { metrics };
-    let app = Router::new()
+    let mut app = Router::new()
         .route("/health", get(health))
         .route("/metrics", get(metrics_handler))
+        .route("/loglevel", post(set_loglevel))
         .with_state(state);
 
+    // Access logging is on by default; it can be disabled for hot paths by
+    // setting `ADMIN_ACCESS_LOG=0`.
+    if access_log_enabled() {
+        app = app.layer(TraceLayer::new_for_http());
+    }
+
     let addr: SocketAddr = "127.0.0.1:9090".parse()?;
     info!(%addr, "Starting admin API server");
     axum::Server::bind(&addr)
@@ -40,10 +49,35 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
+fn access_log_enabled() -> bool {
+    !matches!(
+        env::var("ADMIN_ACCESS_LOG").ok().as_deref(),
+        Some("0") | Some("false")
+    )
+}
+
 async fn health() -> Json<HealthResponse> {
     Json(HealthResponse { status: "ok" })
 }
 
+#[derive(Deserialize)]
+struct LogLevelRequest {
+    level: String,
+}
+
+async fn set_loglevel(Json(req): Json<LogLevelRequest>) -> impl IntoResponse {
+    match core_tracing::reload_log_filter(&req.level) {
+        Ok(()) => {
+            info!(level = %req.level, "log level updated");
+            (StatusCode::OK, format!("log level set to {}", req.level))
+        }
+        Err(err) => {
+            error!(?err, "failed to update log level");
+            (StatusCode::BAD_REQUEST, err.to_string())
+        }
+    }
+}
+
 async fn metrics_handler(State(state): State<AppState>) -> impl IntoResponse {
     match state.metrics.gather() {
         Ok(body) => {
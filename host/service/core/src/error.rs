@@ -1,8 +1,10 @@
-use opentelemetry::trace::TraceError;
-use prometheus::Error as PrometheusError;
-use std::string::FromUtf8Error;
 use thiserror::Error;
-use tracing::subscriber::SetGlobalDefaultError;
+#[cfg(feature = "std")]
+use {
+    opentelemetry::metrics::MetricsError, opentelemetry::trace::TraceError,
+    prometheus::Error as PrometheusError, std::string::FromUtf8Error,
+    tracing::subscriber::SetGlobalDefaultError,
+};
 
 /// Convenient result alias for core operations.
 pub type CoreResult<T> = Result<T, CoreError>;
@@ -19,18 +21,74 @@ pub enum CoreError {
     RingEmpty,
 
     /// Errors that occur while registering or manipulating metrics.
+    #[cfg(feature = "std")]
     #[error("metrics error: {0}")]
     Metrics(#[from] PrometheusError),
 
     /// Errors when converting UTF-8 metric responses.
+    #[cfg(feature = "std")]
     #[error("utf8 conversion error: {0}")]
     Utf8(#[from] FromUtf8Error),
 
     /// Tracing subscriber initialization failure.
+    #[cfg(feature = "std")]
     #[error("tracing initialization failed: {0}")]
     Tracing(#[from] SetGlobalDefaultError),
 
     /// Errors while configuring OpenTelemetry exporters.
+    #[cfg(feature = "std")]
     #[error("opentelemetry error: {0}")]
     OpenTelemetry(#[from] TraceError),
+
+    /// Errors while configuring the OpenTelemetry metrics pipeline.
+    #[cfg(feature = "std")]
+    #[error("opentelemetry metrics error: {0}")]
+    OpenTelemetryMetrics(#[from] MetricsError),
+
+    /// A runtime log-filter reload was requested with an invalid directive or
+    /// before tracing was initialized.
+    #[cfg(feature = "std")]
+    #[error("log filter error: {0}")]
+    LogFilter(String),
+}
+
+/// Common interface for subsystem error types that carry a stable,
+/// machine-readable reason code.
+///
+/// The reason string is used to label the `io_errors_total` metric, so it must
+/// be drawn from a small, fixed set of values to keep metric cardinality
+/// bounded — it should name the *kind* of failure, never interpolate a return
+/// code or message.
+pub trait IoError {
+    /// Canonical reason code for this error, suitable as a metrics label.
+    fn reason(&self) -> &'static str;
+}
+
+/// Errors raised while driving the NVMe device.
+#[derive(Debug, Error)]
+pub enum NvmeError {
+    /// A command did not complete within its deadline.
+    #[error("nvme command timed out")]
+    Timeout,
+
+    /// The submission queue had no free slots.
+    #[error("nvme submission queue is full")]
+    QueueFull,
+
+    /// The controller completed a command with a non-zero status code.
+    #[error("nvme command failed with status {status:#06x}")]
+    Status {
+        /// NVMe completion status field.
+        status: u16,
+    },
+}
+
+impl IoError for NvmeError {
+    fn reason(&self) -> &'static str {
+        match self {
+            NvmeError::Timeout => "nvme_timeout",
+            NvmeError::QueueFull => "nvme_queue_full",
+            NvmeError::Status { .. } => "nvme_status",
+        }
+    }
 }
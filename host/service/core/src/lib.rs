@@ -1,10 +1,23 @@
 //! Core primitives shared across host service components.
+//!
+//! With the default `std` feature the crate exposes the full host-service
+//! surface (metrics, tracing, Prometheus export). Building with
+//! `default-features = false` drops those heavy dependencies and compiles the
+//! pure data structures — [`rings`], [`types`], and a reduced [`error`] module
+//! — as `#![no_std]` + `alloc`, so the same primitives can run inside a
+//! constrained firmware/SmartNIC context.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
 pub mod error;
-pub mod metrics;
 pub mod rings;
-pub mod tracing;
 pub mod types;
 
-pub use error::{CoreError, CoreResult};
+#[cfg(feature = "std")]
+pub mod metrics;
+#[cfg(feature = "std")]
+pub mod tracing;
+
+pub use error::{CoreError, CoreResult, IoError, NvmeError};
 pub use types::{IoDesc, IoFlags, IoOp};
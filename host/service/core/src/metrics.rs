@@ -1,10 +1,24 @@
-use crate::error::{CoreError, CoreResult};
+use crate::error::{CoreError, CoreResult, IoError};
+use crate::tracing::otel_resource;
 use crate::types::IoOp;
 use once_cell::sync::Lazy;
+use opentelemetry::global;
+use opentelemetry::metrics::MeterProvider;
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::{
+    metrics::{
+        reader::{DefaultAggregationSelector, DefaultTemporalitySelector},
+        PeriodicReader, SdkMeterProvider,
+    },
+    runtime::Tokio,
+};
 use prometheus::{
-    Encoder, Histogram, HistogramOpts, IntCounter, IntCounterVec, IntGauge, Opts, Registry,
-    TextEncoder,
+    core::Collector, Encoder, Gauge, GaugeVec, Histogram, HistogramOpts, IntCounter, IntCounterVec,
+    IntGauge, IntGaugeVec, Opts, Registry, TextEncoder,
 };
+use std::time::Duration;
+use tokio::task::JoinHandle;
 
 static METRICS: Lazy<Metrics> = Lazy::new(|| Metrics::new().expect("metrics initialization"));
 
@@ -21,6 +35,11 @@ pub struct Metrics {
     pub nvme_queue_depth: IntGauge,
     pub nvme_timeouts_total: IntCounter,
     pub rdma_cq_overflow_total: IntCounter,
+    pub host_cpu_utilization: Gauge,
+    pub host_resident_memory_bytes: IntGauge,
+    pub device_outstanding_io: IntGaugeVec,
+    pub device_temperature_celsius: GaugeVec,
+    pub device_wear_percent: GaugeVec,
 }
 
 impl Metrics {
@@ -66,6 +85,45 @@ impl Metrics {
         ))?;
         registry.register(Box::new(rdma_cq_overflow_total.clone()))?;
 
+        let host_cpu_utilization = Gauge::with_opts(Opts::new(
+            "host_cpu_utilization",
+            "CPU utilization of the host service thread as a fraction in [0, 1]",
+        ))?;
+        registry.register(Box::new(host_cpu_utilization.clone()))?;
+
+        let host_resident_memory_bytes = IntGauge::with_opts(Opts::new(
+            "host_resident_memory_bytes",
+            "Resident memory of the host service process in bytes",
+        ))?;
+        registry.register(Box::new(host_resident_memory_bytes.clone()))?;
+
+        let device_outstanding_io = IntGaugeVec::new(
+            Opts::new(
+                "device_outstanding_io",
+                "Outstanding IO operations per NVMe namespace",
+            ),
+            &["namespace"],
+        )?;
+        registry.register(Box::new(device_outstanding_io.clone()))?;
+
+        let device_temperature_celsius = GaugeVec::new(
+            Opts::new(
+                "device_temperature_celsius",
+                "SMART composite temperature per NVMe namespace in degrees Celsius",
+            ),
+            &["namespace"],
+        )?;
+        registry.register(Box::new(device_temperature_celsius.clone()))?;
+
+        let device_wear_percent = GaugeVec::new(
+            Opts::new(
+                "device_wear_percent",
+                "SMART wear indicator per NVMe namespace as a percentage used",
+            ),
+            &["namespace"],
+        )?;
+        registry.register(Box::new(device_wear_percent.clone()))?;
+
         Ok(Self {
             registry,
             io_latency_seconds,
@@ -73,6 +131,11 @@ impl Metrics {
             nvme_queue_depth,
             nvme_timeouts_total,
             rdma_cq_overflow_total,
+            host_cpu_utilization,
+            host_resident_memory_bytes,
+            device_outstanding_io,
+            device_temperature_celsius,
+            device_wear_percent,
         })
     }
 
@@ -91,10 +154,14 @@ impl Metrics {
         self.io_latency_seconds.observe(seconds);
     }
 
-    /// Increment the IO error counter for the provided operation and reason.
-    pub fn inc_io_error(&self, op: IoOp, reason: &str) {
+    /// Increment the IO error counter for the provided operation and error.
+    ///
+    /// The error's canonical [`IoError::reason`] code is used as the `reason`
+    /// label so transports no longer pass free-form strings that would
+    /// fragment the metric cardinality.
+    pub fn inc_io_error(&self, op: IoOp, err: &impl IoError) {
         self.io_errors_total
-            .with_label_values(&[op.as_str(), reason])
+            .with_label_values(&[op.as_str(), err.reason()])
             .inc();
     }
 
@@ -113,8 +180,194 @@ impl Metrics {
         self.rdma_cq_overflow_total.inc();
     }
 
+    /// Apply a resource sample to the host and per-namespace device gauges.
+    pub fn update_resource_sample(&self, sample: &ResourceSample) {
+        self.host_cpu_utilization.set(sample.cpu_utilization);
+        self.host_resident_memory_bytes
+            .set(sample.resident_memory_bytes as i64);
+        for ns in &sample.namespaces {
+            let label = ns.namespace_id.to_string();
+            self.device_outstanding_io
+                .with_label_values(&[&label])
+                .set(ns.outstanding_io as i64);
+            self.device_temperature_celsius
+                .with_label_values(&[&label])
+                .set(ns.temperature_celsius);
+            self.device_wear_percent
+                .with_label_values(&[&label])
+                .set(ns.wear_percent);
+        }
+    }
+
     /// Provide read-only access to the underlying registry.
     pub fn registry(&self) -> &Registry {
         &self.registry
     }
+
+    /// Install an OpenTelemetry metrics pipeline that periodically pushes the
+    /// IO metrics (`io_latency_seconds` sum and count, `io_errors_total`,
+    /// `nvme_queue_depth`, `nvme_timeouts_total`, `rdma_cq_overflow_total`) to
+    /// an OTLP collector. The host and device resource gauges are not exported
+    /// through this pipeline.
+    ///
+    /// A [`PeriodicReader`] exports every `interval` using the same
+    /// `service.name` resource as the trace pipeline built by
+    /// [`init_tracing`](crate::tracing::init_tracing). Observable instruments
+    /// read the current values straight out of the Prometheus handles, so this
+    /// gives push-based delivery for environments that cannot scrape the
+    /// `/metrics` endpoint. The returned [`MeterGuard`] owns the installed
+    /// provider and flushes the [`PeriodicReader`] on drop; keep it alive for
+    /// as long as metrics should be exported.
+    pub fn install_otlp_meter(
+        &self,
+        service_name: &str,
+        endpoint: &str,
+        interval: Duration,
+    ) -> CoreResult<MeterGuard> {
+        let exporter = opentelemetry_otlp::new_exporter()
+            .tonic()
+            .with_endpoint(endpoint.to_string())
+            .build_metrics_exporter(
+                Box::new(DefaultAggregationSelector::new()),
+                Box::new(DefaultTemporalitySelector::new()),
+            )?;
+        let reader = PeriodicReader::builder(exporter, Tokio)
+            .with_interval(interval)
+            .build();
+        let provider = SdkMeterProvider::builder()
+            .with_reader(reader)
+            .with_resource(otel_resource(service_name))
+            .build();
+
+        let meter = provider.meter("azure_storage_offload_core");
+
+        // Export the histogram's cumulative sum and count as monotonic
+        // counters so downstream rate()/average-latency math stays valid; a
+        // gauge of the ever-growing sum would be meaningless.
+        let latency_sum = self.io_latency_seconds.clone();
+        meter
+            .f64_observable_counter("io_latency_seconds_sum")
+            .with_description("Cumulative sum of IO latency samples in seconds")
+            .with_callback(move |observer| observer.observe(latency_sum.get_sample_sum(), &[]))
+            .init();
+
+        let latency_count = self.io_latency_seconds.clone();
+        meter
+            .u64_observable_counter("io_latency_seconds_count")
+            .with_description("Cumulative count of observed IO latency samples")
+            .with_callback(move |observer| observer.observe(latency_count.get_sample_count(), &[]))
+            .init();
+
+        let errors = self.io_errors_total.clone();
+        meter
+            .u64_observable_counter("io_errors_total")
+            .with_description("Count of IO errors grouped by operation and reason")
+            .with_callback(move |observer| {
+                for family in errors.collect() {
+                    for metric in family.get_metric() {
+                        let attributes: Vec<KeyValue> = metric
+                            .get_label()
+                            .iter()
+                            .map(|label| {
+                                KeyValue::new(
+                                    label.get_name().to_string(),
+                                    label.get_value().to_string(),
+                                )
+                            })
+                            .collect();
+                        observer.observe(metric.get_counter().get_value() as u64, &attributes);
+                    }
+                }
+            })
+            .init();
+
+        let queue_depth = self.nvme_queue_depth.clone();
+        meter
+            .i64_observable_gauge("nvme_queue_depth")
+            .with_description("Current NVMe queue depth observed by the service")
+            .with_callback(move |observer| observer.observe(queue_depth.get(), &[]))
+            .init();
+
+        let timeouts = self.nvme_timeouts_total.clone();
+        meter
+            .u64_observable_counter("nvme_timeouts_total")
+            .with_description("Total NVMe command timeouts observed")
+            .with_callback(move |observer| observer.observe(timeouts.get(), &[]))
+            .init();
+
+        let cq_overflow = self.rdma_cq_overflow_total.clone();
+        meter
+            .u64_observable_counter("rdma_cq_overflow_total")
+            .with_description("Total RDMA completion queue overflow events")
+            .with_callback(move |observer| observer.observe(cq_overflow.get(), &[]))
+            .init();
+
+        global::set_meter_provider(provider.clone());
+        Ok(MeterGuard { provider })
+    }
+}
+
+/// Guard returned from [`Metrics::install_otlp_meter`] that owns the installed
+/// [`SdkMeterProvider`] and flushes its [`PeriodicReader`] when dropped.
+pub struct MeterGuard {
+    provider: SdkMeterProvider,
+}
+
+impl Drop for MeterGuard {
+    fn drop(&mut self) {
+        // Flush and stop the periodic reader so buffered metrics are exported.
+        let _ = self.provider.shutdown();
+    }
+}
+
+/// Health snapshot for a single NVMe namespace.
+#[derive(Debug, Clone)]
+pub struct NamespaceHealth {
+    /// Namespace identifier the stats belong to.
+    pub namespace_id: u32,
+    /// Number of IO operations currently outstanding against the namespace.
+    pub outstanding_io: u64,
+    /// SMART composite temperature in degrees Celsius.
+    pub temperature_celsius: f64,
+    /// SMART wear indicator as a percentage used.
+    pub wear_percent: f64,
+}
+
+/// A single sample of host and device resource utilization.
+#[derive(Debug, Clone, Default)]
+pub struct ResourceSample {
+    /// CPU utilization of the host service thread as a fraction in `[0, 1]`.
+    pub cpu_utilization: f64,
+    /// Resident memory of the host service process in bytes.
+    pub resident_memory_bytes: u64,
+    /// Per-namespace device health.
+    pub namespaces: Vec<NamespaceHealth>,
+}
+
+/// Pluggable backend that produces [`ResourceSample`]s.
+///
+/// Implementors can read from sysfs, issue NVMe admin commands, or return a
+/// canned sample for tests; the collector is agnostic to the source.
+pub trait ResourceSource: Send + 'static {
+    /// Gather the current host and device resource utilization.
+    fn sample(&self) -> ResourceSample;
+}
+
+/// Spawn a background task that samples `source` every `interval` and publishes
+/// the results into the global [`Metrics`] gauges.
+///
+/// The returned [`JoinHandle`] runs until it is aborted; dropping it detaches
+/// the collector without stopping it.
+pub fn spawn_resource_collector<S: ResourceSource>(
+    source: S,
+    interval: Duration,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            let sample = source.sample();
+            metrics().update_resource_sample(&sample);
+        }
+    })
 }
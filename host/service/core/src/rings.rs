@@ -1,7 +1,8 @@
 use crate::error::{CoreError, CoreResult};
-use std::cell::UnsafeCell;
-use std::mem::MaybeUninit;
-use std::sync::atomic::{AtomicUsize, Ordering};
+use alloc::vec::Vec;
+use core::cell::UnsafeCell;
+use core::mem::MaybeUninit;
+use core::sync::atomic::{AtomicUsize, Ordering};
 
 /// A lock-free single-producer single-consumer ring buffer backed by atomics.
 ///
@@ -48,6 +49,35 @@ impl<T> SpscRing<T> {
         Ok(())
     }
 
+    /// Push as many values as will fit from `items`, draining them front-to-back.
+    ///
+    /// The `tail` is loaded once with [`Ordering::Acquire`] and the available
+    /// slots computed as `capacity - (head - tail)`. Up to that many elements
+    /// are copied into the buffer and then published with a *single*
+    /// [`Ordering::Release`] store of the advanced `head`, which acts as the
+    /// release fence for the whole batch: the consumer cannot observe the new
+    /// `head` until every payload has been written. Consumed elements are
+    /// removed from the front of `items`; the return value is how many were
+    /// pushed.
+    pub fn push_batch(&self, items: &mut Vec<T>) -> usize {
+        let head = self.head.load(Ordering::Relaxed);
+        let tail = self.tail.load(Ordering::Acquire);
+        let slots = self.capacity - head.wrapping_sub(tail);
+        let n = slots.min(items.len());
+        if n == 0 {
+            return 0;
+        }
+
+        for (offset, value) in items.drain(..n).enumerate() {
+            let index = head.wrapping_add(offset) % self.capacity;
+            unsafe {
+                (*self.buffer[index].get()).write(value);
+            }
+        }
+        self.head.store(head.wrapping_add(n), Ordering::Release);
+        n
+    }
+
     /// Pop a value from the ring if one is available.
     pub fn pop(&self) -> CoreResult<T> {
         let tail = self.tail.load(Ordering::Relaxed);
@@ -62,6 +92,32 @@ impl<T> SpscRing<T> {
         Ok(value)
     }
 
+    /// Pop up to `max` values into `out`, appending them in FIFO order.
+    ///
+    /// Mirrors [`push_batch`](Self::push_batch): `head` is loaded once with
+    /// [`Ordering::Acquire`], up to `min(max, available)` elements are read out
+    /// of the buffer, and `tail` is advanced with a *single*
+    /// [`Ordering::Release`] store after every read completes so the producer
+    /// never reuses a slot before it has been fully consumed. Returns how many
+    /// elements were popped.
+    pub fn pop_batch(&self, out: &mut Vec<T>, max: usize) -> usize {
+        let tail = self.tail.load(Ordering::Relaxed);
+        let head = self.head.load(Ordering::Acquire);
+        let available = head.wrapping_sub(tail);
+        let n = available.min(max);
+        if n == 0 {
+            return 0;
+        }
+
+        for offset in 0..n {
+            let index = tail.wrapping_add(offset) % self.capacity;
+            let value = unsafe { (*self.buffer[index].get()).assume_init_read() };
+            out.push(value);
+        }
+        self.tail.store(tail.wrapping_add(n), Ordering::Release);
+        n
+    }
+
     /// Returns the number of elements currently stored in the ring.
     pub fn len(&self) -> usize {
         let head = self.head.load(Ordering::Acquire);
@@ -94,7 +150,7 @@ impl<T> Drop for SpscRing<T> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::SpscRing;
     use crate::error::CoreError;
@@ -125,6 +181,31 @@ mod tests {
         assert!(matches!(ring.push(30), Err(CoreError::RingFull)));
     }
 
+    #[test]
+    fn push_batch_respects_capacity_and_drains_items() {
+        let ring = SpscRing::with_capacity(4);
+        let mut items = vec![1, 2, 3, 4, 5, 6];
+        assert_eq!(ring.push_batch(&mut items), 4);
+        assert_eq!(items, vec![5, 6]);
+        assert_eq!(ring.len(), 4);
+        assert_eq!(ring.push_batch(&mut items), 0);
+        assert_eq!(items, vec![5, 6]);
+    }
+
+    #[test]
+    fn pop_batch_returns_fifo_up_to_max() {
+        let ring = SpscRing::with_capacity(8);
+        let mut items = vec![10, 20, 30, 40, 50];
+        assert_eq!(ring.push_batch(&mut items), 5);
+
+        let mut out = Vec::new();
+        assert_eq!(ring.pop_batch(&mut out, 3), 3);
+        assert_eq!(out, vec![10, 20, 30]);
+        assert_eq!(ring.pop_batch(&mut out, 10), 2);
+        assert_eq!(out, vec![10, 20, 30, 40, 50]);
+        assert_eq!(ring.pop_batch(&mut out, 4), 0);
+    }
+
     #[test]
     fn supports_concurrent_single_producer_consumer() {
         let ring = Arc::new(SpscRing::with_capacity(32));
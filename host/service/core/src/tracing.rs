@@ -1,14 +1,121 @@
-use crate::error::CoreResult;
+use crate::error::{CoreError, CoreResult};
+use once_cell::sync::OnceCell;
 use opentelemetry::KeyValue;
 use opentelemetry_otlp::WithExportConfig;
 use opentelemetry_sdk::{resource::Resource, runtime::Tokio, trace::Config as TraceConfig};
 use std::env;
+use std::path::PathBuf;
+use tracing_appender::non_blocking::WorkerGuard;
 use tracing_opentelemetry::OpenTelemetryLayer;
-use tracing_subscriber::{layer::SubscriberExt, EnvFilter, Registry};
+use tracing_subscriber::{layer::SubscriberExt, reload, EnvFilter, Layer, Registry};
 
-/// Guard returned from [`init_tracing`] that keeps global tracing state alive.
+/// Reload handles for each installed sink's per-layer [`EnvFilter`], populated
+/// by [`init_tracing`] and driven at runtime by [`reload_log_filter`].
+///
+/// Each sink keeps its own filter — preserving the independent per-sink levels
+/// from [`TracingConfig`] — and all of them are reloaded together so
+/// `POST /loglevel` can raise (or lower) verbosity across every sink at once.
+static RELOAD_HANDLES: OnceCell<Vec<reload::Handle<EnvFilter, Registry>>> = OnceCell::new();
+
+/// Build the OpenTelemetry [`Resource`] shared by the trace and metrics
+/// pipelines so both export under the same `service.name`.
+pub fn otel_resource(service_name: &str) -> Resource {
+    Resource::new(vec![KeyValue::new(
+        "service.name",
+        service_name.to_string(),
+    )])
+}
+
+/// Configuration for the plain stdout fmt sink.
+pub struct StdoutSink {
+    /// Whether the sink is installed.
+    pub enabled: bool,
+    /// `EnvFilter` directive applied to this sink only.
+    pub filter: String,
+}
+
+/// Configuration for the rotating structured-JSON file sink.
+pub struct FileSink {
+    /// Whether the sink is installed.
+    pub enabled: bool,
+    /// `EnvFilter` directive applied to this sink only.
+    pub filter: String,
+    /// Directory the daily-rotated log files are written to.
+    pub directory: PathBuf,
+    /// File name prefix for each rotated log file.
+    pub file_name_prefix: String,
+}
+
+/// Configuration for the OTLP trace-export sink.
+pub struct OtlpSink {
+    /// Whether the sink is installed.
+    pub enabled: bool,
+    /// `EnvFilter` directive applied to this sink only.
+    pub filter: String,
+    /// Collector endpoint spans are exported to.
+    pub endpoint: String,
+}
+
+/// Composable multi-sink tracing configuration.
+///
+/// Each sink can be enabled independently and carries its own level filter, so
+/// operators can, for example, keep stdout at `info` while streaming `debug`
+/// JSON to a file and exporting only warnings over OTLP.
+pub struct TracingConfig {
+    /// Plain stdout fmt sink.
+    pub stdout: StdoutSink,
+    /// Rotating structured-JSON file sink.
+    pub file: Option<FileSink>,
+    /// OTLP trace-export sink.
+    pub otlp: Option<OtlpSink>,
+}
+
+impl Default for TracingConfig {
+    fn default() -> Self {
+        Self {
+            stdout: StdoutSink {
+                enabled: true,
+                filter: default_filter(),
+            },
+            file: None,
+            otlp: None,
+        }
+    }
+}
+
+impl TracingConfig {
+    /// Build a configuration from the process environment, preserving the
+    /// historical behavior: stdout enabled plus an OTLP sink when
+    /// `OTEL_EXPORTER_OTLP_ENDPOINT` is set. Each sink inherits `RUST_LOG`
+    /// (or `info`) as its filter.
+    pub fn from_env() -> Self {
+        let otlp = env::var("OTEL_EXPORTER_OTLP_ENDPOINT")
+            .ok()
+            .map(|endpoint| OtlpSink {
+                enabled: true,
+                filter: default_filter(),
+                endpoint,
+            });
+        Self {
+            stdout: StdoutSink {
+                enabled: true,
+                filter: default_filter(),
+            },
+            file: None,
+            otlp,
+        }
+    }
+}
+
+fn default_filter() -> String {
+    env::var("RUST_LOG").unwrap_or_else(|_| "info".to_string())
+}
+
+/// Guard returned from [`init_tracing`] that keeps global tracing state alive
+/// and flushes every installed sink on drop.
 pub struct TracingGuard {
     otel_installed: bool,
+    _worker_guards: Vec<WorkerGuard>,
 }
 
 impl Drop for TracingGuard {
@@ -16,50 +123,95 @@ impl Drop for TracingGuard {
         if self.otel_installed {
             opentelemetry::global::shutdown_tracer_provider();
         }
+        // `_worker_guards` flush their non-blocking writers on drop.
     }
 }
 
-/// Initialize tracing with stdout logging and optional OpenTelemetry export.
-pub fn init_tracing(service_name: &str) -> CoreResult<TracingGuard> {
-    let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
-    let fmt_layer = tracing_subscriber::fmt::layer();
+/// Initialize tracing from the supplied [`TracingConfig`], composing every
+/// enabled sink into a single subscriber.
+///
+/// Returns a [`TracingGuard`] that shuts down the OTLP exporter and flushes the
+/// file writer when dropped. Re-initialization is idempotent: if a global
+/// subscriber is already installed the call succeeds without replacing it.
+pub fn init_tracing(service_name: &str, config: TracingConfig) -> CoreResult<TracingGuard> {
+    let mut layers = Vec::new();
+    let mut worker_guards = Vec::new();
+    let mut reload_handles = Vec::new();
+    let mut otel_installed = false;
+
+    if config.stdout.enabled {
+        let (filter, handle) = reload::Layer::new(EnvFilter::new(config.stdout.filter));
+        reload_handles.push(handle);
+        let layer = tracing_subscriber::fmt::layer().with_filter(filter).boxed();
+        layers.push(layer);
+    }
 
-    let otlp_endpoint = env::var("OTEL_EXPORTER_OTLP_ENDPOINT").ok();
+    if let Some(file) = config.file.filter(|f| f.enabled) {
+        let appender =
+            tracing_appender::rolling::daily(&file.directory, &file.file_name_prefix);
+        let (writer, guard) = tracing_appender::non_blocking(appender);
+        worker_guards.push(guard);
+        let (filter, handle) = reload::Layer::new(EnvFilter::new(file.filter));
+        reload_handles.push(handle);
+        let layer = tracing_subscriber::fmt::layer()
+            .json()
+            .with_writer(writer)
+            .with_filter(filter)
+            .boxed();
+        layers.push(layer);
+    }
 
-    if let Some(endpoint) = otlp_endpoint {
+    if let Some(otlp) = config.otlp.filter(|o| o.enabled) {
         let exporter = opentelemetry_otlp::new_exporter()
             .tonic()
-            .with_endpoint(endpoint);
+            .with_endpoint(otlp.endpoint);
         let tracer = opentelemetry_otlp::new_pipeline()
             .tracing()
-            .with_trace_config(TraceConfig::default().with_resource(Resource::new(vec![
-                KeyValue::new("service.name", service_name.to_string()),
-            ])))
+            .with_trace_config(TraceConfig::default().with_resource(otel_resource(service_name)))
             .with_exporter(exporter)
             .install_batch(Tokio)?;
+        let (filter, handle) = reload::Layer::new(EnvFilter::new(otlp.filter));
+        reload_handles.push(handle);
+        let layer = OpenTelemetryLayer::new(tracer).with_filter(filter).boxed();
+        layers.push(layer);
+        otel_installed = true;
+    }
 
-        let otel_layer = OpenTelemetryLayer::new(tracer);
-        let subscriber = Registry::default()
-            .with(env_filter)
-            .with(fmt_layer)
-            .with(otel_layer);
-
-        tracing::subscriber::set_global_default(subscriber)?;
-        Ok(TracingGuard {
-            otel_installed: true,
-        })
-    } else {
-        let subscriber = Registry::default().with(env_filter).with(fmt_layer);
-        if let Err(err) = tracing::subscriber::set_global_default(subscriber) {
-            if !err
-                .to_string()
-                .contains("global default subscriber has already been set")
-            {
-                return Err(err.into());
-            }
+    let subscriber = Registry::default().with(layers);
+    if let Err(err) = tracing::subscriber::set_global_default(subscriber) {
+        if !err
+            .to_string()
+            .contains("global default trace dispatcher has already been set")
+        {
+            return Err(err.into());
         }
-        Ok(TracingGuard {
-            otel_installed: false,
-        })
+    } else {
+        // Only expose the handles once we own the global subscriber.
+        let _ = RELOAD_HANDLES.set(reload_handles);
+    }
+
+    Ok(TracingGuard {
+        otel_installed,
+        _worker_guards: worker_guards,
+    })
+}
+
+/// Reload every sink's log filter at runtime without restarting the process.
+///
+/// Parses `directive` as an [`EnvFilter`] (same syntax as `RUST_LOG`) and swaps
+/// it into each sink installed by [`init_tracing`], so verbosity can be raised
+/// or lowered across all sinks at once. Returns an error if the directive is
+/// invalid or tracing has not been initialized yet.
+pub fn reload_log_filter(directive: &str) -> CoreResult<()> {
+    let handles = RELOAD_HANDLES
+        .get()
+        .ok_or_else(|| CoreError::LogFilter("tracing is not initialized".to_string()))?;
+    for handle in handles {
+        let filter = EnvFilter::try_new(directive)
+            .map_err(|err| CoreError::LogFilter(err.to_string()))?;
+        handle
+            .reload(filter)
+            .map_err(|err| CoreError::LogFilter(err.to_string()))?;
     }
+    Ok(())
 }
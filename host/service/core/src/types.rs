@@ -1,6 +1,6 @@
+use core::fmt;
+use core::task::Waker;
 use serde::{Deserialize, Serialize};
-use std::fmt;
-use std::task::Waker;
 
 /// The type of IO operation requested by the host.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -1,8 +1,37 @@
 //! RDMA transport bindings leveraging a C shim.
 
 use anyhow::Result;
+use azure_storage_offload_core::{metrics, IoError, IoOp};
+use thiserror::Error;
 use tracing::info;
 
+/// Errors surfaced by the RDMA transport, wrapping the C shim return codes.
+#[derive(Debug, Error)]
+pub enum RdmaError {
+    /// `rdma_client_initialize` returned a non-zero status.
+    #[error("rdma_client_initialize failed: rc={rc}")]
+    Initialize {
+        /// Return code from the C shim.
+        rc: i32,
+    },
+
+    /// `rdma_client_post_write` returned a non-zero status.
+    #[error("rdma_client_post_write failed: rc={rc}")]
+    PostWrite {
+        /// Return code from the C shim.
+        rc: i32,
+    },
+}
+
+impl IoError for RdmaError {
+    fn reason(&self) -> &'static str {
+        match self {
+            RdmaError::Initialize { .. } => "rdma_initialize",
+            RdmaError::PostWrite { .. } => "rdma_post_write",
+        }
+    }
+}
+
 #[link(name = "rdma_client")]
 extern "C" {
     fn rdma_client_initialize() -> i32;
@@ -16,7 +45,7 @@ extern "C" {
 pub fn initialize() -> Result<()> {
     let rc = unsafe { rdma_client_initialize() };
     if rc != 0 {
-        anyhow::bail!("rdma_client_initialize failed: {rc}");
+        return Err(RdmaError::Initialize { rc }.into());
     }
     info!("RDMA client initialized");
     Ok(())
@@ -34,7 +63,9 @@ pub fn post_write(queue: &str, data: &[u8]) -> Result<()> {
         )
     };
     if rc != 0 {
-        anyhow::bail!("rdma_client_post_write failed: {rc}");
+        let err = RdmaError::PostWrite { rc };
+        metrics::metrics().inc_io_error(IoOp::Write, &err);
+        return Err(err.into());
     }
     info!(queue, len = data.len(), "RDMA post write completed");
     Ok(())
@@ -1,9 +1,44 @@
 //! TCP transport bindings for Azure Storage Offload.
 
 use anyhow::Result;
+use azure_storage_offload_core::IoError;
+use thiserror::Error;
 use tracing::info;
 
+/// Errors surfaced by the TCP transport.
+#[derive(Debug, Error)]
+pub enum TcpError {
+    /// Establishing the connection to the endpoint failed.
+    #[error("tcp connect to {endpoint} failed: rc={rc}")]
+    Connect {
+        /// Endpoint that was being dialed.
+        endpoint: String,
+        /// Return code from the underlying socket call.
+        rc: i32,
+    },
+}
+
+impl IoError for TcpError {
+    fn reason(&self) -> &'static str {
+        match self {
+            TcpError::Connect { .. } => "tcp_connect",
+        }
+    }
+}
+
 pub fn connect(endpoint: &str) -> Result<()> {
+    use std::net::TcpStream;
+
     info!(%endpoint, "Establishing TCP transport");
-    Ok(())
+    match TcpStream::connect(endpoint) {
+        Ok(_stream) => {
+            info!(%endpoint, "TCP transport established");
+            Ok(())
+        }
+        Err(err) => Err(TcpError::Connect {
+            endpoint: endpoint.to_string(),
+            rc: err.raw_os_error().unwrap_or(-1),
+        }
+        .into()),
+    }
 }